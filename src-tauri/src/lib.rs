@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
 use sysinfo::Disks;
 use tauri::Emitter;
 
@@ -32,6 +36,10 @@ pub struct FileItem {
     last_modified: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     children: Option<Vec<FileItem>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    symlink_target: Option<String>,
+    #[serde(default)]
+    broken: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -41,12 +49,135 @@ pub struct ScanProgress {
     progress: f32,
 }
 
-// Helper function to format bytes
-fn format_bytes(bytes: u64) -> String {
-    const KB: u64 = 1024;
-    const MB: u64 = KB * 1024;
-    const GB: u64 = MB * 1024;
-    const TB: u64 = GB * 1024;
+// Shared state for in-flight scans: the cancel flag of whichever scan is currently running, and
+// a cache of previously scanned directories keyed by (path, max_depth) so re-browsing the same
+// folder is an O(1) lookup instead of a re-walk, as long as the directory's mtime hasn't changed
+// since. `scan_drive` and `get_directory_contents` recurse to different depths, so the depth is
+// part of the key — otherwise whichever command ran first would poison the other's entry with
+// sizes computed at the wrong depth.
+//
+// Each scan gets its own `Arc<AtomicBool>` rather than sharing one flag: `current_cancel` only
+// tracks which flag `cancel_scan()` should flip next. A scan that's still running keeps its own
+// clone of the flag it was started with, so a later scan replacing `current_cancel` with a fresh
+// one can never silently un-cancel a scan that's already in flight.
+#[derive(Default)]
+pub struct ScanState {
+    current_cancel: Arc<Mutex<Arc<AtomicBool>>>,
+    cache: Arc<Mutex<HashMap<(String, usize), (SystemTime, Vec<FileItem>)>>>,
+}
+
+// Start a new scan: create its own cancel flag, install it as the one `cancel_scan()` will flip,
+// and return the clone the scan itself should check.
+fn begin_scan(state: &ScanState) -> Arc<AtomicBool> {
+    let cancel = Arc::new(AtomicBool::new(false));
+    *state.current_cancel.lock().unwrap() = cancel.clone();
+    cancel
+}
+
+// Look up `(path, max_depth)` in the scan cache, returning its cached items only if the
+// directory's mtime still matches what was cached (i.e. nothing has changed since).
+fn cached_contents(
+    cache: &Mutex<HashMap<(String, usize), (SystemTime, Vec<FileItem>)>>,
+    path: &str,
+    max_depth: usize,
+) -> Option<Vec<FileItem>> {
+    let mtime = fs::metadata(path).and_then(|m| m.modified()).ok()?;
+    let cache = cache.lock().unwrap();
+    let (cached_mtime, cached_items) = cache.get(&(path.to_string(), max_depth))?;
+    if *cached_mtime == mtime {
+        Some(cached_items.clone())
+    } else {
+        None
+    }
+}
+
+// The cache stores items formatted under whatever mode was active when they were computed, so a
+// cache hit re-derives each `size` string from the preserved `size_bytes` under the mode the
+// caller asked for this time.
+fn reformat_items(items: Vec<FileItem>, mode: UnitMode) -> Vec<FileItem> {
+    items
+        .into_iter()
+        .map(|mut item| {
+            item.size = format_bytes(item.size_bytes, mode);
+            item
+        })
+        .collect()
+}
+
+// Refresh the scan cache entry for `(path, max_depth)` with freshly computed items, keyed to
+// its current mtime.
+fn update_cache(
+    cache: &Mutex<HashMap<(String, usize), (SystemTime, Vec<FileItem>)>>,
+    path: &str,
+    max_depth: usize,
+    items: &[FileItem],
+) {
+    if let Ok(mtime) = fs::metadata(path).and_then(|m| m.modified()) {
+        cache
+            .lock()
+            .unwrap()
+            .insert((path.to_string(), max_depth), (mtime, items.to_vec()));
+    }
+}
+
+// Cancel whichever scan is currently running. Checked cooperatively inside the rayon closures
+// of `scan_drive` and `get_directory_contents`, so the cancellation takes effect on the next
+// entry processed rather than instantly.
+#[tauri::command]
+fn cancel_scan(state: tauri::State<ScanState>) -> Result<(), String> {
+    state
+        .current_cancel
+        .lock()
+        .unwrap()
+        .store(true, std::sync::atomic::Ordering::Relaxed);
+    Ok(())
+}
+
+// User-selectable unit system for displaying sizes. `Binary` is the default and matches the
+// app's original behavior (1024-based divisors), just with the correct KiB/MiB/GiB/TiB suffixes.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum UnitMode {
+    #[default]
+    Binary,
+    Decimal,
+    Bytes,
+}
+
+// Format a byte count using the given unit mode; `size_bytes` on the returned structs always
+// carries the raw value, this is only the display string.
+fn format_bytes(bytes: u64, mode: UnitMode) -> String {
+    match mode {
+        UnitMode::Binary => format_bytes_binary(bytes),
+        UnitMode::Decimal => format_bytes_decimal(bytes),
+        UnitMode::Bytes => format_bytes_exact(bytes),
+    }
+}
+
+fn format_bytes_binary(bytes: u64) -> String {
+    const KIB: u64 = 1024;
+    const MIB: u64 = KIB * 1024;
+    const GIB: u64 = MIB * 1024;
+    const TIB: u64 = GIB * 1024;
+
+    if bytes >= TIB {
+        format!("{:.2} TiB", bytes as f64 / TIB as f64)
+    } else if bytes >= GIB {
+        format!("{:.2} GiB", bytes as f64 / GIB as f64)
+    } else if bytes >= MIB {
+        format!("{:.2} MiB", bytes as f64 / MIB as f64)
+    } else if bytes >= KIB {
+        format!("{:.2} KiB", bytes as f64 / KIB as f64)
+    } else {
+        format!("{} bytes", bytes)
+    }
+}
+
+fn format_bytes_decimal(bytes: u64) -> String {
+    const KB: u64 = 1000;
+    const MB: u64 = KB * 1000;
+    const GB: u64 = MB * 1000;
+    const TB: u64 = GB * 1000;
 
     if bytes >= TB {
         format!("{:.2} TB", bytes as f64 / TB as f64)
@@ -61,9 +192,24 @@ fn format_bytes(bytes: u64) -> String {
     }
 }
 
+// Raw byte count, grouped into thousands with commas for readability (e.g. "1,234,567 bytes").
+fn format_bytes_exact(bytes: u64) -> String {
+    let digits = bytes.to_string();
+    let mut grouped = String::new();
+    for (i, ch) in digits.chars().rev().enumerate() {
+        if i > 0 && i % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(ch);
+    }
+    let grouped: String = grouped.chars().rev().collect();
+    format!("{} bytes", grouped)
+}
+
 // Get all available drives
 #[tauri::command]
-fn get_drives() -> Result<Vec<Drive>, String> {
+fn get_drives(unit_mode: Option<UnitMode>) -> Result<Vec<Drive>, String> {
+    let mode = unit_mode.unwrap_or_default();
     let disks = Disks::new_with_refreshed_list();
     let mut drives = Vec::new();
 
@@ -137,9 +283,9 @@ fn get_drives() -> Result<Vec<Drive>, String> {
         drives.push(Drive {
             name: display_name,
             path: mount_point,
-            total_space: format_bytes(total),
-            used_space: format_bytes(used),
-            free_space: format_bytes(actual_available),
+            total_space: format_bytes(total, mode),
+            used_space: format_bytes(used, mode),
+            free_space: format_bytes(actual_available, mode),
             usage_percentage,
         });
     }
@@ -147,26 +293,174 @@ fn get_drives() -> Result<Vec<Drive>, String> {
     Ok(drives)
 }
 
-// Calculate directory size recursively (with depth limit for performance)
-fn calculate_dir_size(path: &Path, max_depth: usize, current_depth: usize) -> u64 {
-    if current_depth > max_depth {
+// Identify a file by (device, inode) on Unix, or (volume serial, file index) on Windows, so
+// multiple hardlinks to the same data can be recognized and counted only once.
+#[cfg(unix)]
+fn file_identity(_path: &Path, metadata: &fs::Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    Some((metadata.dev(), metadata.ino()))
+}
+
+#[cfg(target_os = "windows")]
+fn file_identity(path: &Path, _metadata: &fs::Metadata) -> Option<(u64, u64)> {
+    use std::os::windows::fs::MetadataExt;
+    // MetadataExt on Windows doesn't expose the file index directly, so open a handle via
+    // GetFileInformationByHandle-backed metadata to recover one. Fall back to None (plain
+    // size summation) if the identity can't be read.
+    let file = fs::File::open(path).ok()?;
+    let meta = file.metadata().ok()?;
+    Some((meta.volume_serial_number()? as u64, meta.file_index()?))
+}
+
+#[cfg(not(any(unix, target_os = "windows")))]
+fn file_identity(_path: &Path, _metadata: &fs::Metadata) -> Option<(u64, u64)> {
+    None
+}
+
+// If `path` is a symlink, resolve its target and whether that target actually exists.
+fn symlink_info(path: &Path) -> (Option<String>, bool) {
+    match fs::read_link(path) {
+        Ok(target) => {
+            let broken = !path.exists();
+            (Some(target.to_string_lossy().to_string()), broken)
+        }
+        Err(_) => (None, false),
+    }
+}
+
+// Extensions common enough that the extension alone is a reliable classification, so opening
+// the file to sniff it would just be wasted I/O.
+fn is_unambiguous_extension(extension: &str) -> bool {
+    matches!(
+        extension,
+        "txt" | "md" | "json" | "xml" | "html" | "css" | "js" | "ts" | "rs" | "py" | "java" | "c" | "cpp" | "h"
+            | "jpg" | "jpeg" | "png" | "gif" | "svg" | "bmp" | "webp"
+            | "mp3" | "wav" | "flac" | "ogg"
+            | "pdf" | "doc" | "docx" | "xls" | "xlsx" | "ppt" | "pptx"
+    )
+}
+
+// Only worth opening a file to sniff its contents once it's big enough to plausibly hold a
+// magic number; anything smaller just falls through to the extension.
+const CONTENT_SNIFF_MIN_BYTES: u64 = 64;
+const CONTENT_SNIFF_HEADER_BYTES: usize = 512;
+
+// Match the first few hundred bytes of a file against known magic-number signatures.
+fn sniff_file_category(path: &Path) -> Option<String> {
+    use std::io::Read;
+
+    let mut file = fs::File::open(path).ok()?;
+    let mut header = [0u8; CONTENT_SNIFF_HEADER_BYTES];
+    let read = file.read(&mut header).ok()?;
+    let header = &header[..read];
+
+    let category = if header.starts_with(b"PK\x03\x04")
+        || header.starts_with(b"PK\x05\x06")
+        || header.starts_with(b"PK\x07\x08")
+    {
+        "archive"
+    } else if header.starts_with(&[0x1f, 0x8b]) {
+        "archive" // gzip
+    } else if header.starts_with(b"7z\xbc\xaf\x27\x1c") {
+        "archive"
+    } else if header.starts_with(b"Rar!\x1a\x07") {
+        "archive"
+    } else if header.starts_with(b"SQLite format 3\0") {
+        "database"
+    } else if header.len() >= 8 && &header[4..8] == b"ftyp" {
+        "video" // mp4/mov family
+    } else if header.starts_with(&[0x1a, 0x45, 0xdf, 0xa3]) {
+        "video" // matroska/webm
+    } else if header.starts_with(b"RIFF") && header.len() >= 12 && &header[8..12] == b"AVI " {
+        "video"
+    } else {
+        return None;
+    };
+
+    Some(category.to_string())
+}
+
+// Classify a file by content when the extension alone is ambiguous or absent, falling back to
+// the extension and then to a generic "file". Extensions like `.dat`/`.bin`/`.iso` are exactly
+// the heterogeneous-content case this exists to disambiguate, so every ambiguous or
+// extension-less file is sniffed individually rather than trusting an earlier file's result.
+fn detect_item_type(path: &Path, size_bytes: u64) -> String {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase());
+
+    if let Some(ext) = &extension {
+        if is_unambiguous_extension(ext) {
+            return ext.clone();
+        }
+    }
+
+    if size_bytes >= CONTENT_SNIFF_MIN_BYTES {
+        if let Some(category) = sniff_file_category(path) {
+            return category;
+        }
+    }
+
+    extension.unwrap_or_else(|| "file".to_string())
+}
+
+// Calculate directory size recursively (with depth limit for performance). `seen` tracks
+// (device, inode) pairs already counted so hardlinked files aren't double-counted; it is
+// shared across sibling directories so the rayon-parallel root scan stays consistent.
+// Symlinks are never recursed into (see below), so the only other thing bounding recursion
+// is `max_depth` — there's no separate cycle-detection path to maintain. `cancel` is checked
+// at every level, not just once per top-level entry, so a single huge subtree (e.g.
+// `node_modules`) can still be interrupted by `cancel_scan()` instead of running to completion.
+fn calculate_dir_size(
+    path: &Path,
+    max_depth: usize,
+    current_depth: usize,
+    seen: &Mutex<HashSet<(u64, u64)>>,
+    cancel: &AtomicBool,
+) -> u64 {
+    use std::sync::atomic::Ordering;
+
+    if current_depth > max_depth || cancel.load(Ordering::Relaxed) {
         return 0;
     }
-    
+
     let mut total_size = 0u64;
-    
+
     if let Ok(entries) = fs::read_dir(path) {
         for entry in entries.flatten() {
-            if let Ok(metadata) = entry.metadata() {
-                if metadata.is_dir() {
-                    total_size += calculate_dir_size(&entry.path(), max_depth, current_depth + 1);
-                } else {
-                    total_size += metadata.len();
+            if cancel.load(Ordering::Relaxed) {
+                break;
+            }
+
+            let entry_path = entry.path();
+            let sym_metadata = match entry_path.symlink_metadata() {
+                Ok(metadata) => metadata,
+                Err(_) => continue,
+            };
+
+            // Symlinks are zero-byte leaves: never recurse through them, so a link back up
+            // its own tree can't cause unbounded recursion or double-count the target's bytes.
+            if sym_metadata.file_type().is_symlink() {
+                continue;
+            }
+
+            if sym_metadata.is_dir() {
+                total_size += calculate_dir_size(&entry_path, max_depth, current_depth + 1, seen, cancel);
+            } else {
+                match file_identity(&entry_path, &sym_metadata) {
+                    Some(identity) => {
+                        let mut seen = seen.lock().unwrap();
+                        if seen.insert(identity) {
+                            total_size += sym_metadata.len();
+                        }
+                    }
+                    None => total_size += sym_metadata.len(),
                 }
             }
         }
     }
-    
+
     total_size
 }
 
@@ -175,14 +469,26 @@ fn calculate_dir_size(path: &Path, max_depth: usize, current_depth: usize) -> u6
 async fn scan_drive<R: tauri::Runtime>(
     window: tauri::Window<R>,
     path: String,
+    state: tauri::State<'_, ScanState>,
+    unit_mode: Option<UnitMode>,
 ) -> Result<String, String> {
     use std::sync::atomic::{AtomicU64, Ordering};
-    use std::sync::Arc;
     use rayon::prelude::*;
 
+    let mode = unit_mode.unwrap_or_default();
+    const MAX_DEPTH: usize = 5;
+
+    if let Some(cached_items) = cached_contents(&state.cache, &path, MAX_DEPTH) {
+        let _ = window.emit("scan-complete", reformat_items(cached_items, mode));
+        return Ok("Scan started".to_string());
+    }
+
+    let cancel = begin_scan(&state);
+    let cache = state.cache.clone();
     let counter = Arc::new(AtomicU64::new(0));
+    let seen_inodes = Arc::new(Mutex::new(HashSet::new()));
     let window_clone = window.clone();
-    
+
     std::thread::spawn(move || {
         // Read all entries first
         let entries: Vec<_> = if let Ok(entries) = fs::read_dir(&path) {
@@ -190,16 +496,20 @@ async fn scan_drive<R: tauri::Runtime>(
         } else {
             Vec::new()
         };
-        
+
         let total_entries = entries.len() as f32;
-        
+
         // Process in parallel for speed
         let root_items: Vec<FileItem> = entries
             .par_iter()
             .filter_map(|entry| {
+                if cancel.load(Ordering::Relaxed) {
+                    return None;
+                }
+
                 let entry_path = entry.path();
                 let name = entry.file_name().to_string_lossy().to_string();
-                
+
                 // Emit progress periodically
                 let count = counter.fetch_add(1, Ordering::Relaxed);
                 if count % 5 == 0 {
@@ -214,45 +524,63 @@ async fn scan_drive<R: tauri::Runtime>(
                         progress,
                     });
                 }
-                
-                let metadata = entry.metadata().ok()?;
-                
-                let (size_bytes, item_type) = if metadata.is_dir() {
+
+                let sym_metadata = entry_path.symlink_metadata().ok()?;
+                let (symlink_target, broken) = if sym_metadata.file_type().is_symlink() {
+                    symlink_info(&entry_path)
+                } else {
+                    (None, false)
+                };
+
+                let (size_bytes, item_type) = if symlink_target.is_some() {
+                    // Symlinks are reported as zero-byte leaves; never follow them into a directory.
+                    (0, "symlink".to_string())
+                } else if sym_metadata.is_dir() {
                     // Calculate directory size with limited depth for speed
-                    let size = calculate_dir_size(&entry_path, 5, 0);
+                    let size = calculate_dir_size(&entry_path, MAX_DEPTH, 0, &seen_inodes, &cancel);
                     (size, "directory".to_string())
                 } else {
-                    (metadata.len(), entry_path.extension()
-                        .and_then(|ext| ext.to_str())
-                        .map(|ext| ext.to_lowercase())
-                        .unwrap_or_else(|| "file".to_string()))
+                    let len = sym_metadata.len();
+                    (len, detect_item_type(&entry_path, len))
                 };
-                
-                // Only include files/folders >= 500MB (500 * 1024 * 1024 bytes)
+
+                // Only include files/folders >= 500MB (500 * 1024 * 1024 bytes), but never
+                // silently drop a broken symlink just because it reports zero bytes — a healthy
+                // symlink to a small file has no business in a "large items" listing, but a
+                // dangling one is worth surfacing regardless of size.
                 const MIN_SIZE_BYTES: u64 = 500 * 1024 * 1024;
-                if size_bytes < MIN_SIZE_BYTES {
+                if size_bytes < MIN_SIZE_BYTES && !broken {
                     return None;
                 }
-                
+
                 Some(FileItem {
                     id: entry_path.to_string_lossy().to_string(),
                     name,
-                    size: format_bytes(size_bytes),
+                    size: format_bytes(size_bytes, mode),
                     size_bytes,
                     item_type,
                     path: entry_path.to_string_lossy().to_string(),
                     last_modified: None,
                     children: None,
+                    symlink_target,
+                    broken,
                 })
             })
             .collect();
         
+        if cancel.load(Ordering::Relaxed) {
+            let _ = window_clone.emit("scan-cancelled", ());
+            return;
+        }
+
         // Sort by size (largest first)
         let mut sorted_items = root_items;
         sorted_items.sort_by(|a, b| b.size_bytes.cmp(&a.size_bytes));
-        
+
+        update_cache(&cache, &path, MAX_DEPTH, &sorted_items);
+
         let _ = window_clone.emit("scan-complete", sorted_items);
-        
+
         let final_count = counter.load(Ordering::Relaxed);
         let _ = window_clone.emit("scan-progress", ScanProgress {
             current_path: "Complete".to_string(),
@@ -266,67 +594,495 @@ async fn scan_drive<R: tauri::Runtime>(
 
 // Get directory contents with calculated sizes (async to prevent UI freeze)
 #[tauri::command]
-async fn get_directory_contents(path: String) -> Result<Vec<FileItem>, String> {
+async fn get_directory_contents(
+    path: String,
+    state: tauri::State<'_, ScanState>,
+    unit_mode: Option<UnitMode>,
+) -> Result<Vec<FileItem>, String> {
     use rayon::prelude::*;
-    
+    use std::sync::atomic::Ordering;
+
+    let mode = unit_mode.unwrap_or_default();
     let dir_path = Path::new(&path);
-    
+    const MAX_DEPTH: usize = 3;
+
     if !dir_path.exists() {
         return Err("Directory does not exist".to_string());
     }
 
+    if let Some(cached_items) = cached_contents(&state.cache, &path, MAX_DEPTH) {
+        return Ok(reformat_items(cached_items, mode));
+    }
+
+    // Start this call's own cancel flag rather than reusing whatever scan ran before it:
+    // otherwise a `cancel_scan()` call aimed at a previous (possibly unrelated, possibly still
+    // running) scan would leave a shared flag set to `true` forever, and every entry below
+    // would be filtered out by the `cancel.load(...)` check.
+    let cancel = begin_scan(&state);
+
     // Read directory entries first (fast)
     let entries: Vec<_> = match fs::read_dir(dir_path) {
         Ok(entries) => entries.flatten().collect(),
         Err(e) => return Err(format!("Failed to read directory: {}", e)),
     };
 
+    let seen_inodes = Mutex::new(HashSet::new());
+
     // Process entries in parallel using rayon
     let items: Vec<FileItem> = entries
         .par_iter()
         .filter_map(|entry| {
+            if cancel.load(Ordering::Relaxed) {
+                return None;
+            }
+
             let path = entry.path();
             let name = entry.file_name().to_string_lossy().to_string();
-            
-            let metadata = entry.metadata().ok()?;
 
-            let (size_bytes, item_type) = if metadata.is_dir() {
+            let sym_metadata = path.symlink_metadata().ok()?;
+            let (symlink_target, broken) = if sym_metadata.file_type().is_symlink() {
+                symlink_info(&path)
+            } else {
+                (None, false)
+            };
+
+            let (size_bytes, item_type) = if symlink_target.is_some() {
+                // Symlinks are reported as zero-byte leaves; never follow them into a directory.
+                (0, "symlink".to_string())
+            } else if sym_metadata.is_dir() {
                 // Calculate directory size with limited depth (3 levels)
-                let size = calculate_dir_size(&path, 3, 0);
+                let size = calculate_dir_size(&path, MAX_DEPTH, 0, &seen_inodes, &cancel);
                 (size, "directory".to_string())
             } else {
-                (metadata.len(), path.extension()
-                    .and_then(|ext| ext.to_str())
-                    .map(|ext| ext.to_lowercase())
-                    .unwrap_or_else(|| "file".to_string()))
+                let len = sym_metadata.len();
+                (len, detect_item_type(&path, len))
             };
 
-            // Only include files/folders >= 500MB (500 * 1024 * 1024 bytes)
+            // Only include files/folders >= 500MB (500 * 1024 * 1024 bytes), but never
+            // silently drop a broken symlink just because it reports zero bytes — a healthy
+            // symlink to a small file has no business in a "large items" listing, but a
+            // dangling one is worth surfacing regardless of size.
             const MIN_SIZE_BYTES: u64 = 500 * 1024 * 1024;
-            if size_bytes < MIN_SIZE_BYTES {
+            if size_bytes < MIN_SIZE_BYTES && !broken {
                 return None;
             }
 
             Some(FileItem {
                 id: path.to_string_lossy().to_string(),
                 name,
-                size: format_bytes(size_bytes),
+                size: format_bytes(size_bytes, mode),
                 size_bytes,
                 item_type,
                 path: path.to_string_lossy().to_string(),
                 last_modified: None,
                 children: None,
+                symlink_target,
+                broken,
             })
         })
         .collect();
 
+    // A cancelled listing is partial, so it is neither cached nor returned as a result.
+    if cancel.load(Ordering::Relaxed) {
+        return Err("Scan cancelled".to_string());
+    }
+
     // Sort by size (largest first)
     let mut sorted_items = items;
     sorted_items.sort_by(|a, b| b.size_bytes.cmp(&a.size_bytes));
 
+    update_cache(&state.cache, &path, MAX_DEPTH, &sorted_items);
+
     Ok(sorted_items)
 }
 
+// Walk a directory tree and collect every regular file's path and size.
+// Used by the duplicate finder, which needs the whole tree up front to bucket by size.
+fn collect_files(path: &Path, out: &mut Vec<(PathBuf, u64)>) {
+    let entries = match fs::read_dir(path) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    for entry in entries.flatten() {
+        let entry_path = entry.path();
+        let metadata = match entry.metadata() {
+            Ok(metadata) => metadata,
+            Err(_) => continue,
+        };
+
+        if metadata.is_dir() {
+            collect_files(&entry_path, out);
+        } else if metadata.is_file() {
+            out.push((entry_path, metadata.len()));
+        }
+    }
+}
+
+// Read up to `PREFIX_HASH_BYTES` from the start of the file and, for larger files, the same
+// amount from the end, hashing both. This is cheap enough to run on every same-size candidate
+// and is usually enough to rule out files that only coincidentally share a size.
+const PREFIX_HASH_BYTES: u64 = 16 * 1024;
+
+fn hash_prefix(path: &Path, file_len: u64) -> Option<u64> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    let mut file = fs::File::open(path).ok()?;
+    let mut hasher = blake3::Hasher::new();
+
+    let head_len = PREFIX_HASH_BYTES.min(file_len) as usize;
+    let mut head = vec![0u8; head_len];
+    file.read_exact(&mut head).ok()?;
+    hasher.update(&head);
+
+    if file_len > PREFIX_HASH_BYTES {
+        let tail_len = PREFIX_HASH_BYTES.min(file_len) as usize;
+        file.seek(SeekFrom::End(-(tail_len as i64))).ok()?;
+        let mut tail = vec![0u8; tail_len];
+        file.read_exact(&mut tail).ok()?;
+        hasher.update(&tail);
+    }
+
+    let digest = hasher.finalize();
+    Some(u64::from_le_bytes(digest.as_bytes()[0..8].try_into().ok()?))
+}
+
+// Hash the full contents of a file, tracking how many bytes were read so callers can report
+// overall progress across a batch of candidates.
+fn hash_full_contents(path: &Path, bytes_hashed: &std::sync::atomic::AtomicU64) -> Option<blake3::Hash> {
+    use std::io::Read;
+    use std::sync::atomic::Ordering;
+
+    let mut file = fs::File::open(path).ok()?;
+    let mut hasher = blake3::Hasher::new();
+    let mut buf = [0u8; 64 * 1024];
+
+    loop {
+        let read = file.read(&mut buf).ok()?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buf[..read]);
+        bytes_hashed.fetch_add(read as u64, Ordering::Relaxed);
+    }
+
+    Some(hasher.finalize())
+}
+
+// Collapse paths that are really the same data on disk (hardlinks to one inode) down to a
+// single representative path, so a group of hardlinks to the same file is never reported as a
+// "duplicate" whose wasted space is actually zero to reclaim.
+fn dedupe_hardlinks(paths: Vec<PathBuf>) -> Vec<PathBuf> {
+    let mut seen_identities = HashSet::new();
+    let mut deduped = Vec::new();
+    for path in paths {
+        let identity = fs::metadata(&path)
+            .ok()
+            .and_then(|metadata| file_identity(&path, &metadata));
+        match identity {
+            Some(identity) => {
+                if seen_identities.insert(identity) {
+                    deduped.push(path);
+                }
+            }
+            None => deduped.push(path),
+        }
+    }
+    deduped
+}
+
+fn file_item_for_duplicate(path: &Path, size_bytes: u64, mode: UnitMode) -> FileItem {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string_lossy().to_string());
+    let item_type = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase())
+        .unwrap_or_else(|| "file".to_string());
+
+    FileItem {
+        id: path.to_string_lossy().to_string(),
+        name,
+        size: format_bytes(size_bytes, mode),
+        size_bytes,
+        item_type,
+        path: path.to_string_lossy().to_string(),
+        last_modified: None,
+        children: None,
+        symlink_target: None,
+        broken: false,
+    }
+}
+
+// Find groups of byte-identical files under `path`. Runs the classic three-stage pipeline:
+// bucket by size, narrow with a cheap prefix/suffix hash, then confirm with a full-content hash.
+#[tauri::command]
+async fn find_duplicates<R: tauri::Runtime>(
+    window: tauri::Window<R>,
+    path: String,
+    unit_mode: Option<UnitMode>,
+) -> Result<String, String> {
+    use rayon::prelude::*;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    let mode = unit_mode.unwrap_or_default();
+
+    std::thread::spawn(move || {
+        let root = Path::new(&path);
+
+        // Stage 1: bucket every file by size, discarding size classes that can't collide.
+        let mut all_files = Vec::new();
+        collect_files(root, &mut all_files);
+
+        let mut by_size: HashMap<u64, Vec<PathBuf>> = HashMap::new();
+        for (file_path, size) in all_files {
+            if size == 0 {
+                continue;
+            }
+            by_size.entry(size).or_default().push(file_path);
+        }
+        by_size.retain(|_, paths| paths.len() > 1);
+
+        let files_scanned = Arc::new(AtomicU64::new(0));
+        let total_candidates: u64 = by_size.values().map(|paths| paths.len() as u64).sum();
+
+        // Stage 2: narrow each size bucket with a cheap prefix/suffix hash.
+        let prefix_groups: Vec<(u64, Vec<PathBuf>)> = by_size
+            .into_par_iter()
+            .flat_map(|(size, paths)| {
+                let mut by_prefix: HashMap<u64, Vec<PathBuf>> = HashMap::new();
+                for candidate in paths {
+                    let scanned = files_scanned.fetch_add(1, Ordering::Relaxed);
+                    if scanned % 25 == 0 {
+                        let progress = if total_candidates > 0 {
+                            (scanned as f32 / total_candidates as f32 * 100.0).min(99.0)
+                        } else {
+                            0.0
+                        };
+                        let _ = window.emit(
+                            "scan-progress",
+                            ScanProgress {
+                                current_path: candidate.to_string_lossy().to_string(),
+                                files_scanned: scanned,
+                                progress,
+                            },
+                        );
+                    }
+
+                    if let Some(prefix_hash) = hash_prefix(&candidate, size) {
+                        by_prefix.entry(prefix_hash).or_default().push(candidate);
+                    }
+                }
+                by_prefix
+                    .into_iter()
+                    .filter(|(_, paths)| paths.len() > 1)
+                    .map(move |(_, paths)| (size, paths))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        // Stage 3: confirm remaining candidates with a full-content hash. This is the expensive
+        // whole-file read over every remaining candidate, so report progress by bytes hashed
+        // rather than leaving the UI sitting at stage 2's final percentage with no feedback.
+        let bytes_hashed = Arc::new(AtomicU64::new(0));
+        let candidates_hashed = Arc::new(AtomicU64::new(0));
+        let total_bytes_to_hash: u64 = prefix_groups
+            .iter()
+            .map(|(size, paths)| size * paths.len() as u64)
+            .sum();
+        let duplicate_groups: Vec<Vec<FileItem>> = prefix_groups
+            .into_par_iter()
+            .flat_map(|(size, paths)| {
+                let mut by_content: HashMap<blake3::Hash, Vec<PathBuf>> = HashMap::new();
+                for candidate in paths {
+                    // The file may have changed since stage 1; skip it rather than hash stale data.
+                    let current_size = match fs::metadata(&candidate) {
+                        Ok(metadata) => metadata.len(),
+                        Err(_) => continue,
+                    };
+                    if current_size != size {
+                        continue;
+                    }
+
+                    let hash = hash_full_contents(&candidate, &bytes_hashed);
+
+                    let hashed = candidates_hashed.fetch_add(1, Ordering::Relaxed);
+                    if hashed % 10 == 0 {
+                        let progress = if total_bytes_to_hash > 0 {
+                            (bytes_hashed.load(Ordering::Relaxed) as f32 / total_bytes_to_hash as f32 * 100.0)
+                                .min(99.0)
+                        } else {
+                            0.0
+                        };
+                        let _ = window.emit(
+                            "scan-progress",
+                            ScanProgress {
+                                current_path: candidate.to_string_lossy().to_string(),
+                                files_scanned: hashed,
+                                progress,
+                            },
+                        );
+                    }
+
+                    if let Some(hash) = hash {
+                        by_content.entry(hash).or_default().push(candidate);
+                    }
+                }
+                by_content
+                    .into_iter()
+                    .map(|(_, paths)| dedupe_hardlinks(paths))
+                    .filter(|paths| paths.len() > 1)
+                    .map(move |paths| {
+                        paths
+                            .into_iter()
+                            .map(|p| file_item_for_duplicate(&p, size, mode))
+                            .collect::<Vec<FileItem>>()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        // Sort so the group wasting the most disk space (size * (count - 1)) comes first.
+        let mut sorted_groups = duplicate_groups;
+        sorted_groups.sort_by(|a, b| {
+            let wasted = |group: &Vec<FileItem>| {
+                group
+                    .first()
+                    .map(|item| item.size_bytes * (group.len() as u64 - 1))
+                    .unwrap_or(0)
+            };
+            wasted(b).cmp(&wasted(a))
+        });
+
+        let _ = window.emit("duplicates-complete", sorted_groups);
+
+        let final_count = files_scanned.load(Ordering::Relaxed);
+        let _ = window.emit(
+            "scan-progress",
+            ScanProgress {
+                current_path: "Complete".to_string(),
+                files_scanned: final_count,
+                progress: 100.0,
+            },
+        );
+    });
+
+    Ok("Scan started".to_string())
+}
+
+// A directory in the tree being checked for emptiness, along with its directory children (never
+// symlinks, which are never recursed into). `is_empty` is computed bottom-up as the tree is
+// built, so the rollup needs no re-stat-ing once a node's children are known.
+struct DirNode {
+    path: PathBuf,
+    is_empty: bool,
+    children: Vec<DirNode>,
+}
+
+// Build the subtree rooted at `path`, classifying each directory as empty (nothing but empty
+// directories anywhere beneath it; a file, symlink or other entry makes it non-empty) as soon as
+// its children are known. Sibling subtrees are independent, so
+// they're recursed into in parallel with rayon.
+fn build_dir_tree(path: &Path) -> DirNode {
+    use rayon::prelude::*;
+
+    let entries: Vec<_> = match fs::read_dir(path) {
+        Ok(entries) => entries.flatten().collect(),
+        // An unreadable directory can't be shown to hold nothing, so it is never reported as empty.
+        Err(_) => {
+            return DirNode {
+                path: path.to_path_buf(),
+                is_empty: false,
+                children: Vec::new(),
+            }
+        }
+    };
+
+    let mut has_non_empty_entry = false;
+    let sub_dirs: Vec<PathBuf> = entries
+        .iter()
+        .filter_map(|entry| {
+            let entry_path = entry.path();
+            let sym_metadata = entry_path.symlink_metadata().ok()?;
+
+            if sym_metadata.file_type().is_symlink() {
+                // Don't recurse through a symlink, and don't let it count as "empty" either.
+                has_non_empty_entry = true;
+                None
+            } else if sym_metadata.is_dir() {
+                Some(entry_path)
+            } else {
+                has_non_empty_entry = true;
+                None
+            }
+        })
+        .collect();
+
+    let children: Vec<DirNode> = sub_dirs.par_iter().map(|dir| build_dir_tree(dir)).collect();
+    let is_empty = !has_non_empty_entry && children.iter().all(|child| child.is_empty);
+
+    DirNode {
+        path: path.to_path_buf(),
+        is_empty,
+        children,
+    }
+}
+
+// Walk the tree top-down, recording the highest directory in each empty chain: once a directory
+// qualifies as empty, its empty descendants are implied and are not reported separately.
+fn collect_empty_roots(node: &DirNode, roots: &mut Vec<PathBuf>) {
+    if node.is_empty {
+        roots.push(node.path.clone());
+    } else {
+        for child in &node.children {
+            collect_empty_roots(child, roots);
+        }
+    }
+}
+
+// Find directories under `path` that contain no files anywhere beneath them, ready to feed into
+// `move_to_trash`. A chain of nested empty directories is reported as a single prunable root.
+#[tauri::command]
+async fn find_empty_folders(path: String, unit_mode: Option<UnitMode>) -> Result<Vec<FileItem>, String> {
+    let mode = unit_mode.unwrap_or_default();
+    let root = Path::new(&path);
+
+    if !root.exists() {
+        return Err("Directory does not exist".to_string());
+    }
+
+    let tree = build_dir_tree(root);
+    let mut roots = Vec::new();
+    collect_empty_roots(&tree, &mut roots);
+
+    let items = roots
+        .into_iter()
+        .map(|dir_path| {
+            let name = dir_path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| dir_path.to_string_lossy().to_string());
+
+            FileItem {
+                id: dir_path.to_string_lossy().to_string(),
+                name,
+                size: format_bytes(0, mode),
+                size_bytes: 0,
+                item_type: "empty-directory".to_string(),
+                path: dir_path.to_string_lossy().to_string(),
+                last_modified: None,
+                children: None,
+                symlink_target: None,
+                broken: false,
+            }
+        })
+        .collect();
+
+    Ok(items)
+}
+
 // Delete a file or directory
 #[tauri::command]
 async fn delete_file_or_directory(path: String) -> Result<String, String> {
@@ -389,10 +1145,14 @@ async fn move_to_trash(path: String) -> Result<String, String> {
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
+        .manage(ScanState::default())
         .invoke_handler(tauri::generate_handler![
             get_drives,
             scan_drive,
             get_directory_contents,
+            find_duplicates,
+            find_empty_folders,
+            cancel_scan,
             delete_file_or_directory,
             move_to_trash,
         ])